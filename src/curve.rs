@@ -0,0 +1,88 @@
+use std::fmt;
+use std::ops::Neg;
+
+use crate::field::{self, PrimeField};
+
+/// A short Weierstrass elliptic curve `y^2 = x^3 + a*x + b` over `BaseField`.
+///
+/// Concrete curves are zero-sized marker types that implement this trait,
+/// so `Point<C>` carries no runtime curve parameters and the curve
+/// coefficients are available wherever `C: Curve` is in scope.
+pub trait Curve: Copy + Clone {
+    type BaseField: PrimeField;
+
+    fn a() -> Self::BaseField;
+    fn b() -> Self::BaseField;
+
+    /// A generator point on the curve, used as a default starting point for
+    /// scalar multiplication.
+    fn generator() -> Point<Self>
+    where
+        Self: Sized;
+
+    /// Checks that the curve `y^2 = x^3 + a*x + b` is nonsingular, i.e. its
+    /// discriminant `4*a^3 + 27*b^2` is nonzero in the base field. Torsion
+    /// computations on a singular curve are meaningless, so callers should
+    /// check this before doing any point arithmetic.
+    fn is_nonsingular() -> bool
+    where
+        Self: Sized,
+    {
+        let four = field::from_u64::<Self::BaseField>(4);
+        let twenty_seven = field::from_u64::<Self::BaseField>(27);
+        let a = Self::a();
+        let b = Self::b();
+        let discriminant = four.mul(a.mul(a).mul(a)).add(twenty_seven.mul(b.mul(b)));
+        discriminant != Self::BaseField::zero()
+    }
+}
+
+/// A point on an elliptic curve `C`, in affine coordinates.
+#[derive(Clone, Copy, Debug)]
+pub struct Point<C: Curve> {
+    pub x: Option<C::BaseField>,
+    pub y: Option<C::BaseField>,
+}
+
+impl<C: Curve> Point<C> {
+    pub fn new(x: Option<C::BaseField>, y: Option<C::BaseField>) -> Self {
+        Point { x, y }
+    }
+
+    pub fn is_at_infinity(&self) -> bool {
+        self.x.is_none() && self.y.is_none()
+    }
+
+    pub fn at_infinity() -> Self {
+        Point { x: None, y: None }
+    }
+}
+
+impl<C: Curve> PartialEq for Point<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl<C: Curve> Neg for Point<C> {
+    type Output = Point<C>;
+
+    /// Negates a point: `-(x, y) = (x, -y)`, and `-O = O`.
+    fn neg(self) -> Point<C> {
+        match self.y {
+            None => self,
+            Some(y) => Point::new(self.x, Some(y.neg())),
+        }
+    }
+}
+
+impl<C: Curve> fmt::Display for Point<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_at_infinity() {
+            write!(f, "Point at infinity")
+        } else {
+            // Safe to unwrap since the point is not at infinity.
+            write!(f, "({}, {})", self.x.unwrap(), self.y.unwrap())
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::curve::{Curve, Point};
+use crate::field::{F5x2, PrimeField};
+
+/// The curve \(y^2 = x^3 + x + 1\) over \(\mathbb{F}_{5^2}\).
+#[derive(Clone, Copy, Debug)]
+pub struct E5x2;
+
+impl Curve for E5x2 {
+    type BaseField = F5x2;
+
+    fn a() -> Self::BaseField {
+        F5x2::new(1, 0)
+    }
+
+    fn b() -> Self::BaseField {
+        F5x2::new(1, 0)
+    }
+
+    fn generator() -> Point<Self> {
+        // Any point on the curve works as a default generator; scan for the
+        // first (x, y) satisfying y^2 = x^3 + a*x + b.
+        for x in F5x2::elements() {
+            let rhs = x.mul(x).mul(x).add(Self::a().mul(x)).add(Self::b());
+            for y in F5x2::elements() {
+                if y.mul(y) == rhs {
+                    return Point::new(Some(x), Some(y));
+                }
+            }
+        }
+        unreachable!("a nonsingular curve always has at least one affine point")
+    }
+}
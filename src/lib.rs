@@ -0,0 +1,8 @@
+pub mod curve;
+pub mod curves;
+pub mod field;
+#[cfg(feature = "pyo3")]
+pub mod python;
+pub mod projective;
+pub mod runtime;
+pub mod torsion;
@@ -0,0 +1,236 @@
+use crate::curve::{Curve, Point};
+use crate::field::{self, PrimeField};
+
+/// A point on curve `C` in standard projective coordinates `(X:Y:Z)`,
+/// representing the affine point `(X/Z, Y/Z)` when `Z != 0`, and the point
+/// at infinity `(0:1:0)` when `Z == 0`.
+///
+/// Addition and doubling on this representation involve only field
+/// multiplications, not the inversion that affine `point_add` needs for
+/// every call — that cost is deferred to a single batched normalization
+/// (see [`batch_normalize`]).
+#[derive(Clone, Copy, Debug)]
+pub struct ProjectivePoint<C: Curve> {
+    pub x: C::BaseField,
+    pub y: C::BaseField,
+    pub z: C::BaseField,
+}
+
+impl<C: Curve> ProjectivePoint<C> {
+    pub fn identity() -> Self {
+        ProjectivePoint {
+            x: C::BaseField::zero(),
+            y: C::BaseField::one(),
+            z: C::BaseField::zero(),
+        }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.z == C::BaseField::zero()
+    }
+
+    pub fn from_affine(p: Point<C>) -> Self {
+        match (p.x, p.y) {
+            (Some(x), Some(y)) => ProjectivePoint {
+                x,
+                y,
+                z: C::BaseField::one(),
+            },
+            _ => ProjectivePoint::identity(),
+        }
+    }
+
+    /// Converts back to an affine point. Involves one field inversion;
+    /// prefer [`batch_normalize`] when converting many points at once.
+    pub fn to_affine(&self) -> Point<C> {
+        if self.is_identity() {
+            return Point::at_infinity();
+        }
+        let inv_z = self.z.inverse();
+        Point::new(Some(self.x.mul(inv_z)), Some(self.y.mul(inv_z)))
+    }
+
+    /// Inversion-free point doubling.
+    pub fn double(self) -> Self {
+        if self.is_identity() || self.y == C::BaseField::zero() {
+            return ProjectivePoint::identity();
+        }
+
+        let three = field::from_u64::<C::BaseField>(3);
+        let four = field::from_u64::<C::BaseField>(4);
+        let eight = field::from_u64::<C::BaseField>(8);
+
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+
+        let two = field::from_u64::<C::BaseField>(2);
+        let w = C::a().mul(z1.mul(z1)).add(three.mul(x1.mul(x1)));
+        let s = y1.mul(z1);
+        let b_val = x1.mul(y1).mul(s);
+        let h = w.mul(w).sub(eight.mul(b_val));
+
+        let x3 = two.mul(h).mul(s);
+        let y3 = w
+            .mul(four.mul(b_val).sub(h))
+            .sub(eight.mul(y1.mul(y1)).mul(s.mul(s)));
+        let z3 = eight.mul(s.mul(s).mul(s));
+
+        ProjectivePoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Inversion-free point addition.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: Self) -> Self {
+        if self.is_identity() {
+            return other;
+        }
+        if other.is_identity() {
+            return self;
+        }
+
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+        let (x2, y2, z2) = (other.x, other.y, other.z);
+
+        let u = y2.mul(z1).sub(y1.mul(z2));
+        let v = x2.mul(z1).sub(x1.mul(z2));
+
+        if v == C::BaseField::zero() {
+            if u == C::BaseField::zero() {
+                return self.double();
+            }
+            return ProjectivePoint::identity();
+        }
+
+        let two = field::from_u64::<C::BaseField>(2);
+        let v2 = v.mul(v);
+        let v3 = v2.mul(v);
+        let w = z1.mul(z2);
+        let x1z2 = x1.mul(z2);
+
+        let a_val = u.mul(u).mul(w).sub(v3).sub(two.mul(v2).mul(x1z2));
+        let x3 = v.mul(a_val);
+        let y3 = u
+            .mul(v2.mul(x1z2).sub(a_val))
+            .sub(v3.mul(y1.mul(z2)));
+        let z3 = v3.mul(w);
+
+        ProjectivePoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+/// Scalar multiplication using the double-and-add algorithm, entirely in
+/// projective coordinates (no field inversions).
+pub fn projective_point_mul<C: Curve>(n: u64, p: ProjectivePoint<C>) -> ProjectivePoint<C> {
+    let mut result = ProjectivePoint::identity();
+    let mut base = p;
+    let mut k = n;
+
+    while k > 0 {
+        if k & 1 == 1 {
+            result = result.add(base);
+        }
+        base = base.double();
+        k >>= 1;
+    }
+
+    result
+}
+
+/// Converts a whole slice of projective points back to affine using
+/// Montgomery's trick: one pass computes prefix products of the `Z`
+/// values, inverts only the final nonzero product once, then walks
+/// backward multiplying out each individual `1/Z`. Points already at
+/// infinity (`Z == 0`) are skipped in the product and normalize for free.
+pub fn batch_normalize<C: Curve>(points: &[ProjectivePoint<C>]) -> Vec<Point<C>> {
+    let mut prefix = Vec::with_capacity(points.len());
+    let mut acc = C::BaseField::one();
+    for p in points {
+        if !p.is_identity() {
+            acc = acc.mul(p.z);
+        }
+        prefix.push(acc);
+    }
+
+    let mut inv_acc = if acc == C::BaseField::zero() {
+        C::BaseField::zero()
+    } else {
+        acc.inverse()
+    };
+
+    let mut result = vec![Point::at_infinity(); points.len()];
+    for i in (0..points.len()).rev() {
+        let p = &points[i];
+        if p.is_identity() {
+            continue;
+        }
+        let prefix_before = if i == 0 {
+            C::BaseField::one()
+        } else {
+            prefix[i - 1]
+        };
+        let inv_z = inv_acc.mul(prefix_before);
+        result[i] = Point::new(Some(p.x.mul(inv_z)), Some(p.y.mul(inv_z)));
+        inv_acc = inv_acc.mul(p.z);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::E5x2;
+    use crate::field::F5x2;
+    use crate::torsion::point_add;
+
+    /// An explicit non-infinity solution of `y^2 = x^3 + x + 1` over
+    /// `F_{5^2}`: `1^2 == 0^3 + 0 + 1`.
+    fn e5x2_point() -> Point<E5x2> {
+        Point::new(Some(F5x2::new(0, 0)), Some(F5x2::new(1, 0)))
+    }
+
+    /// Scalar multiplication via repeated affine `point_add`, for comparison
+    /// against the projective `point_mul` path.
+    fn affine_point_mul(n: u64, p: Point<E5x2>) -> Point<E5x2> {
+        let mut result = Point::at_infinity();
+        let mut base = p;
+        let mut k = n;
+        while k > 0 {
+            if k & 1 == 1 {
+                result = point_add(result, base);
+            }
+            base = point_add(base, base);
+            k >>= 1;
+        }
+        result
+    }
+
+    #[test]
+    fn projective_point_mul_matches_affine() {
+        let p = e5x2_point();
+        for n in 0..10 {
+            let projective_result =
+                projective_point_mul(n, ProjectivePoint::from_affine(p)).to_affine();
+            assert_eq!(projective_result, affine_point_mul(n, p));
+        }
+    }
+
+    #[test]
+    fn batch_normalize_matches_individual_to_affine() {
+        let p = e5x2_point();
+        let points: Vec<ProjectivePoint<E5x2>> = (0..6)
+            .map(|n| projective_point_mul(n, ProjectivePoint::from_affine(p)))
+            .collect();
+
+        let batched = batch_normalize(&points);
+        let individual: Vec<Point<E5x2>> = points.iter().map(|pt| pt.to_affine()).collect();
+        assert_eq!(batched, individual);
+    }
+}
@@ -0,0 +1,105 @@
+//! Python bindings for the torsion computation, exposed as a `math`
+//! module via pyo3. Bound to the demo curve `E5x2: y^2 = x^3 + x + 1`
+//! over \(\mathbb{F}_{5^2}\), since pyo3 classes must be concrete types.
+
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+use crate::curve::Point;
+use crate::curves::E5x2;
+use crate::field::F5x2;
+use crate::torsion;
+
+/// A point on the curve `y^2 = x^3 + x + 1` over \(\mathbb{F}_{5^2}\).
+#[pyclass(name = "Point")]
+#[derive(Clone, Copy)]
+pub struct PyPoint {
+    inner: Point<E5x2>,
+}
+
+#[pymethods]
+impl PyPoint {
+    /// Builds an affine point `(a_x + b_x*t, a_y + b_y*t)`.
+    #[new]
+    fn new(a_x: u64, b_x: u64, a_y: u64, b_y: u64) -> Self {
+        PyPoint {
+            inner: Point::new(Some(F5x2::new(a_x, b_x)), Some(F5x2::new(a_y, b_y))),
+        }
+    }
+
+    #[staticmethod]
+    fn at_infinity() -> Self {
+        PyPoint {
+            inner: Point::at_infinity(),
+        }
+    }
+
+    fn is_at_infinity(&self) -> bool {
+        self.inner.is_at_infinity()
+    }
+
+    fn __str__(&self) -> String {
+        format!("{}", self.inner)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{}", self.inner)
+    }
+}
+
+#[pyfunction]
+fn point_add(p: &PyPoint, q: &PyPoint) -> PyPoint {
+    PyPoint {
+        inner: torsion::point_add(p.inner, q.inner),
+    }
+}
+
+#[pyfunction]
+fn point_mul(n: u64, p: &PyPoint) -> PyPoint {
+    PyPoint {
+        inner: torsion::point_mul(n, p.inner),
+    }
+}
+
+#[pyfunction]
+fn find_full_r_torsion_points(r: u64) -> Vec<PyPoint> {
+    torsion::find_full_r_torsion_points::<E5x2>(r)
+        .into_iter()
+        .map(|inner| PyPoint { inner })
+        .collect()
+}
+
+/// A basis for the full `r`-torsion subgroup (see [`torsion::TorsionBasis`]).
+#[pyclass(name = "TorsionBasis")]
+struct PyTorsionBasis {
+    #[pyo3(get)]
+    p: PyPoint,
+    #[pyo3(get)]
+    q: Option<PyPoint>,
+    #[pyo3(get)]
+    is_full: bool,
+}
+
+#[pyfunction]
+fn find_r_torsion_basis(r: u64) -> PyTorsionBasis {
+    let basis = torsion::find_r_torsion_basis::<E5x2>(r);
+    PyTorsionBasis {
+        p: PyPoint { inner: basis.p },
+        q: basis.q.map(|inner| PyPoint { inner }),
+        is_full: basis.is_full,
+    }
+}
+
+/// Registers the `math` module exposing `Point`, `point_add`,
+/// `point_mul`, `find_full_r_torsion_points` and `find_r_torsion_basis` to
+/// Python.
+#[pymodule]
+fn math(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyPoint>()?;
+    m.add_class::<PyTorsionBasis>()?;
+    m.add_function(wrap_pyfunction!(point_add, m)?)?;
+    m.add_function(wrap_pyfunction!(point_mul, m)?)?;
+    m.add_function(wrap_pyfunction!(find_full_r_torsion_points, m)?)?;
+    m.add_function(wrap_pyfunction!(find_r_torsion_basis, m)?)?;
+    Ok(())
+}
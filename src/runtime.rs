@@ -0,0 +1,350 @@
+//! Runtime-parameterized counterpart to [`crate::field`] and
+//! [`crate::curve`], used by the CLI where `p`, `c`, `a` and `b` are
+//! ordinary command-line flags rather than compile-time const generics.
+
+use std::fmt;
+
+use crate::field::mod_inverse;
+use crate::torsion::{divisors, is_prime};
+
+/// An element `a + b*t` of `F_{p^2} = F_p[t] / (t^2 - c)`, with `p` and `c`
+/// carried at runtime instead of fixed as const generic parameters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RuntimeFp2 {
+    pub p: u64,
+    pub c: u64,
+    a: u64,
+    b: u64,
+}
+
+impl RuntimeFp2 {
+    pub fn new(p: u64, c: u64, a: i64, b: i64) -> Self {
+        RuntimeFp2 {
+            p,
+            c: c % p,
+            a: a.rem_euclid(p as i64) as u64,
+            b: b.rem_euclid(p as i64) as u64,
+        }
+    }
+
+    pub fn zero(p: u64, c: u64) -> Self {
+        RuntimeFp2::new(p, c, 0, 0)
+    }
+
+    pub fn one(p: u64, c: u64) -> Self {
+        RuntimeFp2::new(p, c, 1, 0)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: Self) -> Self {
+        RuntimeFp2::new(
+            self.p,
+            self.c,
+            (self.a + other.a) as i64,
+            (self.b + other.b) as i64,
+        )
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub(self, other: Self) -> Self {
+        RuntimeFp2::new(
+            self.p,
+            self.c,
+            self.a as i64 - other.a as i64,
+            self.b as i64 - other.b as i64,
+        )
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, other: Self) -> Self {
+        // (a + b*t) * (c' + d*t) = (ac' + c*bd) + (ad + bc')t, since t^2 = c.
+        let (a, b) = (self.a as i128, self.b as i128);
+        let (cc, d) = (other.a as i128, other.b as i128);
+        let c_const = self.c as i128;
+
+        let ac = a * cc;
+        let bd = b * d;
+        let ad_plus_bc = a * d + b * cc;
+        let new_a = ac + c_const * bd;
+        RuntimeFp2::new(self.p, self.c, new_a as i64, ad_plus_bc as i64)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn neg(self) -> Self {
+        RuntimeFp2::new(self.p, self.c, -(self.a as i64), -(self.b as i64))
+    }
+
+    pub fn inverse(self) -> Self {
+        // For a + b*t, the inverse is (a - b*t)/(a^2 - c*b^2), since t^2 = c.
+        let a = self.a as i128;
+        let b = self.b as i128;
+        let c = self.c as i128;
+        let denominator = (a * a - c * b * b).rem_euclid(self.p as i128) as u64;
+        let inv_denominator = mod_inverse(denominator, self.p);
+
+        let new_a = (a as i64) * inv_denominator as i64;
+        let new_b = -(self.b as i64) * inv_denominator as i64;
+        RuntimeFp2::new(self.p, self.c, new_a, new_b)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn div(self, other: Self) -> Self {
+        self.mul(other.inverse())
+    }
+
+    /// Every element of `F_{p^2}`, in ascending `(a, b)` order.
+    pub fn elements(p: u64, c: u64) -> Vec<Self> {
+        let mut elements = Vec::new();
+        for a in 0..p {
+            for b in 0..p {
+                elements.push(RuntimeFp2::new(p, c, a as i64, b as i64));
+            }
+        }
+        elements
+    }
+}
+
+impl fmt::Display for RuntimeFp2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.a, self.b) {
+            (0, 0) => write!(f, "0"),
+            (a, 0) => write!(f, "{}", a),
+            (0, b) => write!(f, "{}t", b),
+            (a, b) => write!(f, "{} + {}t", a, b),
+        }
+    }
+}
+
+/// A point on `y^2 = x^3 + a*x + b` over a [`RuntimeFp2`] field.
+#[derive(Clone, Copy, Debug)]
+pub struct RuntimePoint {
+    pub x: Option<RuntimeFp2>,
+    pub y: Option<RuntimeFp2>,
+}
+
+impl RuntimePoint {
+    pub fn new(x: Option<RuntimeFp2>, y: Option<RuntimeFp2>) -> Self {
+        RuntimePoint { x, y }
+    }
+
+    pub fn is_at_infinity(&self) -> bool {
+        self.x.is_none() && self.y.is_none()
+    }
+
+    pub fn at_infinity() -> Self {
+        RuntimePoint { x: None, y: None }
+    }
+}
+
+impl fmt::Display for RuntimePoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_at_infinity() {
+            write!(f, "Point at infinity")
+        } else {
+            write!(f, "({}, {})", self.x.unwrap(), self.y.unwrap())
+        }
+    }
+}
+
+/// The curve `y^2 = x^3 + a*x + b` over `F_{p^2}`, with every parameter
+/// supplied at runtime (mirroring [`crate::curve::Curve`], whose `a`/`b`/
+/// `BaseField` are fixed at compile time instead).
+#[derive(Clone, Copy, Debug)]
+pub struct RuntimeCurve {
+    pub p: u64,
+    pub c: u64,
+    pub a: RuntimeFp2,
+    pub b: RuntimeFp2,
+}
+
+impl RuntimeCurve {
+    pub fn new(p: u64, c: u64, a: i64, b: i64) -> Self {
+        RuntimeCurve {
+            p,
+            c,
+            a: RuntimeFp2::new(p, c, a, 0),
+            b: RuntimeFp2::new(p, c, b, 0),
+        }
+    }
+
+    /// Checks that the curve is nonsingular, i.e. its discriminant
+    /// `4*a^3 + 27*b^2` is nonzero in the base field. Mirrors
+    /// [`crate::curve::Curve::is_nonsingular`] for the runtime-parameterized
+    /// curve used by the CLI.
+    pub fn is_nonsingular(&self) -> bool {
+        let four = RuntimeFp2::new(self.p, self.c, 4, 0);
+        let twenty_seven = RuntimeFp2::new(self.p, self.c, 27, 0);
+        let discriminant = four
+            .mul(self.a.mul(self.a).mul(self.a))
+            .add(twenty_seven.mul(self.b.mul(self.b)));
+        discriminant != RuntimeFp2::zero(self.p, self.c)
+    }
+}
+
+fn assert_nonsingular(curve: &RuntimeCurve) {
+    assert!(
+        curve.is_nonsingular(),
+        "curve is singular (discriminant 4a^3 + 27b^2 = 0); torsion is undefined"
+    );
+}
+
+/// Adds two points on `curve`.
+pub fn point_add(p: RuntimePoint, q: RuntimePoint, curve: &RuntimeCurve) -> RuntimePoint {
+    assert_nonsingular(curve);
+
+    if p.is_at_infinity() {
+        return q;
+    }
+    if q.is_at_infinity() {
+        return p;
+    }
+
+    let (x1, y1) = (p.x.unwrap(), p.y.unwrap());
+    let (x2, y2) = (q.x.unwrap(), q.y.unwrap());
+
+    if x1 == x2 && y1 != y2 {
+        return RuntimePoint::at_infinity();
+    }
+
+    // Doubling a point of order 2 (y1 == 0): the tangent line is vertical,
+    // so the result is the point at infinity.
+    if x1 == x2 && y1 == y2 && y1 == RuntimeFp2::zero(curve.p, curve.c) {
+        return RuntimePoint::at_infinity();
+    }
+
+    let two = RuntimeFp2::new(x1.p, x1.c, 2, 0);
+    let three = RuntimeFp2::new(x1.p, x1.c, 3, 0);
+
+    let lambda = if x1 == x2 && y1 == y2 {
+        let numerator = x1.mul(x1).mul(three).add(curve.a);
+        let denominator = y1.mul(two);
+        numerator.div(denominator)
+    } else {
+        let numerator = y2.sub(y1);
+        let denominator = x2.sub(x1);
+        numerator.div(denominator)
+    };
+
+    let x3 = lambda.mul(lambda).sub(x1).sub(x2);
+    let y3 = lambda.mul(x1.sub(x3)).sub(y1);
+    RuntimePoint::new(Some(x3), Some(y3))
+}
+
+/// Scalar multiplication using the double-and-add algorithm.
+pub fn point_mul(n: u64, p: RuntimePoint, curve: &RuntimeCurve) -> RuntimePoint {
+    assert_nonsingular(curve);
+
+    let mut result = RuntimePoint::at_infinity();
+    let mut base = p;
+    let mut k = n;
+
+    while k > 0 {
+        if k & 1 == 1 {
+            result = point_add(result, base, curve);
+        }
+        base = point_add(base, base, curve);
+        k >>= 1;
+    }
+
+    result
+}
+
+/// All points on `curve`.
+pub fn curve_points(curve: &RuntimeCurve) -> Vec<RuntimePoint> {
+    let field_elements = RuntimeFp2::elements(curve.p, curve.c);
+    let mut points = Vec::new();
+    for x in &field_elements {
+        let rhs = x.mul(*x).mul(*x).add(curve.a.mul(*x)).add(curve.b);
+        for y in &field_elements {
+            if y.mul(*y) == rhs {
+                points.push(RuntimePoint::new(Some(*x), Some(*y)));
+            }
+        }
+    }
+    points
+}
+
+/// Finds all full `r`-torsion points, i.e. points `P` such that `r*P = O`.
+pub fn find_full_r_torsion_points(r: u64, curve: &RuntimeCurve) -> Vec<RuntimePoint> {
+    assert_nonsingular(curve);
+
+    let mut torsion_points: Vec<RuntimePoint> = curve_points(curve)
+        .into_iter()
+        .filter(|point| point_mul(r, *point, curve).is_at_infinity())
+        .collect();
+    torsion_points.push(RuntimePoint::at_infinity());
+    torsion_points
+}
+
+/// A basis for the full `r`-torsion subgroup of `curve`, mirroring
+/// [`crate::torsion::find_r_torsion_basis`] for the runtime-parameterized
+/// curve used by the CLI.
+pub struct RuntimeTorsionBasis {
+    pub p: RuntimePoint,
+    pub q: Option<RuntimePoint>,
+    pub is_full: bool,
+}
+
+fn runtime_point_order(point: RuntimePoint, r: u64, curve: &RuntimeCurve) -> u64 {
+    for d in divisors(r) {
+        if point_mul(d, point, curve).is_at_infinity() {
+            return d;
+        }
+    }
+    unreachable!("r*point = O, so r itself is always a valid divisor");
+}
+
+pub fn find_r_torsion_basis(r: u64, curve: &RuntimeCurve) -> RuntimeTorsionBasis {
+    let torsion_points = find_full_r_torsion_points(r, curve);
+
+    let order_r_points: Vec<RuntimePoint> = torsion_points
+        .iter()
+        .filter(|point| !point.is_at_infinity())
+        .copied()
+        .filter(|point| runtime_point_order(*point, r, curve) == r)
+        .collect();
+
+    let p = match order_r_points.first() {
+        Some(p) => *p,
+        None => {
+            return RuntimeTorsionBasis {
+                p: RuntimePoint::at_infinity(),
+                q: None,
+                is_full: false,
+            };
+        }
+    };
+
+    let multiples_of_p: Vec<RuntimePoint> =
+        (0..r).map(|k| point_mul(k, p, curve)).collect();
+    let q = order_r_points
+        .iter()
+        .find(|point| !multiples_of_p.iter().any(|m| points_eq(m, point)))
+        .copied();
+
+    match q {
+        Some(q) => {
+            if is_prime(r) {
+                assert_eq!(
+                    torsion_points.len() as u64,
+                    r * r,
+                    "found two independent order-r points but |E[r]| != r^2"
+                );
+            }
+            RuntimeTorsionBasis {
+                p,
+                q: Some(q),
+                is_full: torsion_points.len() as u64 == r * r,
+            }
+        }
+        None => RuntimeTorsionBasis {
+            p,
+            q: None,
+            is_full: false,
+        },
+    }
+}
+
+fn points_eq(p: &RuntimePoint, q: &RuntimePoint) -> bool {
+    p.x == q.x && p.y == q.y
+}
@@ -0,0 +1,372 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::curve::{Curve, Point};
+use crate::field::PrimeField;
+use crate::projective::{batch_normalize, projective_point_mul, ProjectivePoint};
+
+fn assert_nonsingular<C: Curve>() {
+    assert!(
+        C::is_nonsingular(),
+        "curve is singular (discriminant 4a^3 + 27b^2 = 0); torsion is undefined"
+    );
+}
+
+/// Adds two points on the elliptic curve `C`.
+pub fn point_add<C: Curve>(p: Point<C>, q: Point<C>) -> Point<C> {
+    assert_nonsingular::<C>();
+
+    if p.is_at_infinity() {
+        return q;
+    }
+    if q.is_at_infinity() {
+        return p;
+    }
+
+    let (x1, y1) = (p.x.unwrap(), p.y.unwrap());
+    let (x2, y2) = (q.x.unwrap(), q.y.unwrap());
+
+    // If x1 == x2 but y1 != y2, the result is the point at infinity.
+    if x1 == x2 && y1 != y2 {
+        return Point::at_infinity();
+    }
+
+    // Doubling a point of order 2 (y1 == 0): the tangent line is vertical,
+    // so the result is the point at infinity.
+    if x1 == x2 && y1 == y2 && y1 == C::BaseField::zero() {
+        return Point::at_infinity();
+    }
+
+    let two = C::BaseField::one().add(C::BaseField::one());
+    let three = two.add(C::BaseField::one());
+
+    let lambda = if x1 == x2 && y1 == y2 {
+        // Point doubling: λ = (3*x1^2 + a) / (2*y1)
+        let numerator = x1.mul(x1).mul(three).add(C::a());
+        let denominator = y1.mul(two);
+        numerator.div(denominator)
+    } else {
+        // Point addition: λ = (y2 - y1) / (x2 - x1)
+        let numerator = y2.sub(y1);
+        let denominator = x2.sub(x1);
+        numerator.div(denominator)
+    };
+
+    let x3 = lambda.mul(lambda).sub(x1).sub(x2);
+    let y3 = lambda.mul(x1.sub(x3)).sub(y1);
+    Point::new(Some(x3), Some(y3))
+}
+
+/// Scalar multiplication using the double-and-add algorithm.
+///
+/// A thin affine wrapper: the double-and-add itself runs in projective
+/// coordinates (see [`crate::projective`]), which needs no field inversions,
+/// and only the final result is converted back to affine.
+pub fn point_mul<C: Curve>(n: u64, p: Point<C>) -> Point<C> {
+    assert_nonsingular::<C>();
+
+    projective_point_mul(n, ProjectivePoint::from_affine(p)).to_affine()
+}
+
+impl<C: Curve> Add for Point<C> {
+    type Output = Point<C>;
+
+    fn add(self, other: Point<C>) -> Point<C> {
+        point_add(self, other)
+    }
+}
+
+impl<C: Curve> Sub for Point<C> {
+    type Output = Point<C>;
+
+    fn sub(self, other: Point<C>) -> Point<C> {
+        point_add(self, -other)
+    }
+}
+
+impl<C: Curve> Mul<u64> for Point<C> {
+    type Output = Point<C>;
+
+    fn mul(self, n: u64) -> Point<C> {
+        point_mul(n, self)
+    }
+}
+
+/// A basis for the full `r`-torsion subgroup \(E[r]\).
+///
+/// For the generic case \(E[r] \cong (\mathbb{Z}/r\mathbb{Z})^2\), `p` and `q`
+/// are two points of exact order `r` with `q` not a multiple of `p`, and
+/// `is_full` is `true` exactly when all \(r^2\) points of \(E[r]\) are
+/// present. For prime `r` such a pair generates the whole subgroup; for
+/// composite `r` the pair is not checked to generate all of \(E[r]\).
+/// If the curve only realizes a
+/// rank-1 subgroup of order `r` over the chosen field, `q` is `None` and
+/// `is_full` is `false`.
+pub struct TorsionBasis<C: Curve> {
+    pub p: Point<C>,
+    pub q: Option<Point<C>>,
+    pub is_full: bool,
+}
+
+/// All divisors of `n`, in ascending order.
+pub(crate) fn divisors(n: u64) -> Vec<u64> {
+    let mut divs = Vec::new();
+    for d in 1..=n {
+        if n.is_multiple_of(d) {
+            divs.push(d);
+        }
+    }
+    divs
+}
+
+pub(crate) fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    (2..n).all(|d| d * d > n || !n.is_multiple_of(d))
+}
+
+/// The exact order of `point`, i.e. the least divisor `d` of `r` with
+/// `d*point = O`. Assumes `r*point = O`, so some divisor of `r` always works.
+fn point_order<C: Curve>(point: Point<C>, r: u64) -> u64 {
+    for d in divisors(r) {
+        if point_mul(d, point).is_at_infinity() {
+            return d;
+        }
+    }
+    unreachable!("r*point = O, so r itself is always a valid divisor");
+}
+
+/// Computes the structure of the full `r`-torsion subgroup \(E[r]\) and
+/// returns a basis for it (see [`TorsionBasis`]).
+pub fn find_r_torsion_basis<C: Curve>(r: u64) -> TorsionBasis<C> {
+    let torsion_points = find_full_r_torsion_points::<C>(r);
+
+    let order_r_points: Vec<Point<C>> = torsion_points
+        .iter()
+        .filter(|point| !point.is_at_infinity())
+        .copied()
+        .filter(|point| point_order(*point, r) == r)
+        .collect();
+
+    let p = match order_r_points.first() {
+        Some(p) => *p,
+        None => {
+            // No point of exact order r: E[r] is trivial over this field.
+            return TorsionBasis {
+                p: Point::at_infinity(),
+                q: None,
+                is_full: false,
+            };
+        }
+    };
+
+    let multiples_of_p: Vec<Point<C>> = (0..r).map(|k| point_mul(k, p)).collect();
+    let q = order_r_points
+        .iter()
+        .find(|point| !multiples_of_p.contains(point))
+        .copied();
+
+    match q {
+        Some(q) => {
+            if is_prime(r) {
+                assert_eq!(
+                    torsion_points.len() as u64,
+                    r * r,
+                    "found two independent order-r points but |E[r]| != r^2"
+                );
+            }
+            TorsionBasis {
+                p,
+                q: Some(q),
+                is_full: torsion_points.len() as u64 == r * r,
+            }
+        }
+        None => TorsionBasis {
+            p,
+            q: None,
+            is_full: false,
+        },
+    }
+}
+
+/// Finds all full r‑torsion points, i.e. points \(P\) such that \(rP = \mathcal{O}\),
+/// by checking all possible points on the curve.
+///
+/// Every candidate is scalar-multiplied by `r` in projective coordinates, so
+/// the scan itself performs no field inversions; the only inversion is the
+/// single batched one in [`batch_normalize`] used to read back which
+/// candidates landed on infinity.
+pub fn find_full_r_torsion_points<C: Curve>(r: u64) -> Vec<Point<C>> {
+    assert_nonsingular::<C>();
+
+    let field_elements = C::BaseField::elements();
+    let mut candidates = Vec::new();
+
+    // Loop over all possible x in the field.
+    for x in field_elements.iter() {
+        // Compute rhs = x^3 + a*x + b.
+        let x_cubed = x.mul(*x).mul(*x);
+        let rhs = x_cubed.add(C::a().mul(*x)).add(C::b());
+
+        // For each possible y in the field, check if y^2 equals rhs.
+        for y in field_elements.iter() {
+            if y.mul(*y) == rhs {
+                candidates.push(Point::new(Some(*x), Some(*y)));
+            }
+        }
+    }
+
+    let scaled: Vec<ProjectivePoint<C>> = candidates
+        .iter()
+        .map(|point| projective_point_mul(r, ProjectivePoint::from_affine(*point)))
+        .collect();
+
+    let mut torsion_points: Vec<Point<C>> = candidates
+        .into_iter()
+        .zip(batch_normalize(&scaled))
+        .filter(|(_, scaled_point)| scaled_point.is_at_infinity())
+        .map(|(point, _)| point)
+        .collect();
+
+    // Include the point at infinity.
+    torsion_points.push(Point::at_infinity());
+    torsion_points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curves::E5x2;
+    use crate::field::{Fp2, F5x2};
+
+    /// `y^2 = x^3 + 2x + 1` over `F_{5^2}`, whose 5-torsion is rank 1.
+    #[derive(Clone, Copy)]
+    struct RankOneCurve;
+
+    impl Curve for RankOneCurve {
+        type BaseField = Fp2<5, 3>;
+
+        fn a() -> Self::BaseField {
+            Fp2::new(2, 0)
+        }
+
+        fn b() -> Self::BaseField {
+            Fp2::new(1, 0)
+        }
+
+        fn generator() -> Point<Self> {
+            Point::at_infinity()
+        }
+    }
+
+    /// A curve with `4*0^3 + 27*0^2 = 0`, i.e. singular.
+    #[derive(Clone, Copy)]
+    struct SingularCurve;
+
+    impl Curve for SingularCurve {
+        type BaseField = F5x2;
+
+        fn a() -> Self::BaseField {
+            F5x2::new(0, 0)
+        }
+
+        fn b() -> Self::BaseField {
+            F5x2::new(0, 0)
+        }
+
+        fn generator() -> Point<Self> {
+            Point::at_infinity()
+        }
+    }
+
+    /// An explicit non-infinity solution of `y^2 = x^3 + x + 1` over
+    /// `F_{5^2}`: `1^2 == 0^3 + 0 + 1`.
+    fn e5x2_point() -> Point<E5x2> {
+        Point::new(Some(F5x2::new(0, 0)), Some(F5x2::new(1, 0)))
+    }
+
+    #[test]
+    fn add_matches_point_add() {
+        let p = e5x2_point();
+        let q = point_mul::<E5x2>(2, p);
+        assert_eq!(p + q, point_add(p, q));
+    }
+
+    #[test]
+    fn sub_is_add_of_negation() {
+        let p = e5x2_point();
+        let q = point_mul::<E5x2>(2, p);
+        assert_eq!(p - q, point_add(p, -q));
+    }
+
+    #[test]
+    fn point_plus_its_negation_is_infinity() {
+        let p = e5x2_point();
+        assert!((p + (-p)).is_at_infinity());
+    }
+
+    #[test]
+    fn mul_by_zero_is_infinity() {
+        let p = e5x2_point();
+        let zero: u64 = 0;
+        assert!((p * zero).is_at_infinity());
+    }
+
+    #[test]
+    fn mul_by_one_is_identity() {
+        let p = e5x2_point();
+        assert_eq!(p * 1, p);
+    }
+
+    #[test]
+    fn mul_matches_point_mul() {
+        let p = e5x2_point();
+        assert_eq!(p * 3, point_mul::<E5x2>(3, p));
+    }
+
+    #[test]
+    #[should_panic(expected = "curve is singular")]
+    fn point_add_rejects_singular_curve() {
+        point_add(Point::<SingularCurve>::at_infinity(), Point::at_infinity());
+    }
+
+    #[test]
+    #[should_panic(expected = "curve is singular")]
+    fn point_mul_rejects_singular_curve() {
+        point_mul(2, Point::<SingularCurve>::at_infinity());
+    }
+
+    #[test]
+    #[should_panic(expected = "curve is singular")]
+    fn find_full_r_torsion_points_rejects_singular_curve() {
+        find_full_r_torsion_points::<SingularCurve>(2);
+    }
+
+    #[test]
+    fn rank_two_basis_generates_all_of_e_r() {
+        let basis = find_r_torsion_basis::<E5x2>(3);
+        assert!(basis.is_full);
+        let q = basis.q.expect("rank-2 subgroup has an independent second point");
+
+        assert_eq!(point_order(basis.p, 3), 3);
+        assert_eq!(point_order(q, 3), 3);
+
+        let generated: Vec<Point<E5x2>> = (0..3)
+            .flat_map(|i| (0..3).map(move |j| (i, j)))
+            .map(|(i, j)| point_mul(i, basis.p) + point_mul(j, q))
+            .collect();
+        assert_eq!(generated.len(), 9);
+        for point in find_full_r_torsion_points::<E5x2>(3) {
+            assert!(generated.contains(&point));
+        }
+    }
+
+    #[test]
+    fn rank_one_basis_has_no_independent_second_point() {
+        let basis = find_r_torsion_basis::<RankOneCurve>(5);
+        assert!(!basis.is_full);
+        assert!(!basis.p.is_at_infinity());
+        assert!(basis.q.is_none());
+        assert_eq!(point_order(basis.p, 5), 5);
+    }
+}